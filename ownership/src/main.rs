@@ -66,6 +66,8 @@ fn main() {
     let (passed_string, len) = calculate_length(String::from("hello"));
 
     println!("The length of '{}' is {}", passed_string, len);
+
+    custom_drop();
 }
 
 fn string_literal() {
@@ -215,3 +217,59 @@ fn calculate_length(s: String) -> (String, usize) {
 
 // In the next chapter, we'll explore a Rust feature for using a value without transfer of
 // ownership, called `References`
+
+// Rust calls `drop` for us automatically when a value goes out of scope, but a type can customize
+// what happens at that point by implementing the `Drop` trait. This is Rust's answer to RAII
+// (resource acquisition is initialization): whatever a type needs to clean up, be it memory, a
+// file handle, or a network connection, can be tied to the value's own lifetime.
+struct CustomResource {
+    name: String,
+}
+
+impl Drop for CustomResource {
+    fn drop(&mut self) {
+        println!("Dropping {}", self.name);
+    }
+}
+
+fn custom_drop() {
+    let _a = CustomResource {
+        name: String::from("a"),
+    };
+    let _b = CustomResource {
+        name: String::from("b"),
+    };
+
+    {
+        let _c = CustomResource {
+            name: String::from("c"),
+        };
+
+        println!("c created inside an inner scope");
+    } // _c is dropped here, printing "Dropping c"
+
+    println!("a and b are still alive");
+
+    // Variables are dropped in the reverse order of their creation, so when `custom_drop` returns,
+    // we'll see "Dropping b" before "Dropping a".
+
+    // Occasionally you'll want to force a value to be dropped early, for example if the value
+    // controls a resource like a lock and you want code in the same scope that follows it to
+    // release that resource. Rust does not let you call the `Drop` trait's `drop` method
+    // manually.
+    let c = CustomResource {
+        name: String::from("c (explicit)"),
+    };
+
+    // c.drop(); // this fails with "explicit use of destructor method" / "explicit destructor
+    // calls not allowed"
+
+    // Instead, you call the `std::mem::drop` function, which is different from the `drop` method
+    // in the `Drop` trait. It takes the value we want to force drop as an argument.
+    drop(c);
+
+    println!("c (explicit) was dropped early, before the end of the scope");
+
+    // When `_a` and `_b` go out of scope at the end of this function, they'll be dropped in the
+    // reverse of the order they were created: `_b` first, then `_a`.
+}
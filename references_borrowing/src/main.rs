@@ -2,6 +2,12 @@ fn main() {
     let s1 = String::from("hello");
     let len = calculate_length(&s1);
     println!("The length of {} is {}", s1, len);
+
+    mutable_references();
+
+    slices();
+
+    dangling_references();
 }
 
 // In this example, we do not have to return the string as a tuple in order to use it again.
@@ -13,3 +19,147 @@ fn main() {
 fn calculate_length(s: &String) -> usize {
     s.len()
 }
+
+// Just as variables are immutable by default, so are references. We're not allowed to modify
+// something we have a reference to, unless we make the reference mutable instead.
+fn mutable_references() {
+    let mut s = String::from("hello");
+
+    change(&mut s);
+
+    println!("{s}");
+
+    // Mutable references have one big restriction: if you have a mutable reference to a value,
+    // you can have no other references to that value at the same time.
+    //
+    // The benefit of having this restriction is that Rust can prevent data races at compile time.
+    // A data race is similar to a race condition, and happens when these three behaviours occur:
+    // - Two or more pointers access the same data at the same time.
+    // - At least one of the pointers is being used to write to the data.
+    // - There's no mechanism being used to synchronize access to the data.
+    //
+    // Data races cause undefined behaviour and can be difficult to diagnose and fix at runtime,
+    // so Rust prevents this problem by refusing to compile code with data races.
+
+    // let r1 = &mut s;
+    // let r2 = &mut s;
+    // println!("{r1}, {r2}"); // this fails with "cannot borrow `s` as mutable more than once at
+    // a time"
+
+    // As always, we can use curly brackets to create a new scope, allowing for multiple mutable
+    // references, just not simultaneous ones.
+    {
+        let r1 = &mut s;
+        r1.push_str(", world");
+    }
+    let r2 = &mut s;
+    r2.push_str("!");
+
+    // Rust also enforces a similar rule for combining mutable and immutable references.
+
+    // let r1 = &s; // no problem
+    // let r2 = &s; // no problem
+    // let r3 = &mut s; // big problem
+    // println!("{r1}, {r2}, and {r3}"); // this fails with "cannot borrow `s` as mutable because
+    // it is also borrowed as immutable"
+
+    // A reference's scope starts from where it is introduced and continues through the last time
+    // that reference is used. This is called a non-lexical lifetime, because the scope doesn't
+    // necessarily extend to the closing curly bracket the way it would with a lexical scope.
+    let r1 = &s;
+    let r2 = &s;
+    println!("{r1} and {r2}");
+    // r1 and r2 are not used after this point, so their scope ends here, even though the
+    // variables themselves are still in scope.
+
+    let r3 = &mut s;
+    println!("{r3}");
+}
+
+fn change(s: &mut String) {
+    s.push_str(", world");
+}
+
+// Slices let you reference a contiguous sequence of elements in a collection rather than the
+// whole collection. A slice is a kind of reference, so it does not have ownership.
+fn slices() {
+    let s = String::from("hello world");
+
+    let word = first_word(&s);
+
+    println!("The first word is: {word}");
+
+    // `word` is a `&str`, a string slice, holding a reference into `s`. Because `first_word`
+    // borrows `s` immutably to produce `word`, and `word` is still in use below, Rust won't let
+    // us take a mutable reference to `s` (such as the one `clear` needs) until `word` is done
+    // being used.
+
+    // s.clear(); // this fails with "cannot borrow `s` as mutable because it is also borrowed as
+    // immutable"
+
+    // println!("the first word is: {word}");
+
+    // Without slices, `word` would just be a `usize` index into `s`, which is a separate value
+    // from `s` itself. There's no guarantee that it's still valid, since nothing ties its
+    // lifetime to the data in `s`. If `s` were cleared or reassigned after we calculated the
+    // index, the index would be meaningless, or worse, point at data that no longer matches what
+    // we originally found.
+    println!("{s} still has a word: {word}");
+
+    // String literals are slices. The type of a string literal is `&str`, a slice pointing to
+    // that specific point of the binary, which is also why string literals are immutable.
+    let _literal: &str = "Hello, world!";
+
+    // Arrays can be sliced too, the same way string slices work.
+    let a = [1, 2, 3, 4, 5];
+    let slice = &a[1..3];
+
+    println!("{:?}", slice);
+    assert_eq!(slice, &[2, 3]);
+}
+
+// Returns a string slice pointing at the first word in `s`, or the whole string if there's no
+// space.
+fn first_word(s: &str) -> &str {
+    let bytes = s.as_bytes();
+
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return &s[0..i];
+        }
+    }
+
+    &s[..]
+}
+
+// In languages with pointers, it's easy to erroneously create a dangling pointer, one that
+// references a location in memory that may have been given to someone else, by freeing some
+// memory while preserving a pointer to it. In Rust, the compiler guarantees that references will
+// never be dangling: if you have a reference to some data, the compiler will ensure that the data
+// will not go out of scope before the reference to the data does.
+fn dangling_references() {
+    let s = no_dangle();
+
+    println!("{s}");
+}
+
+// fn dangle() -> &String {
+//     let s = String::from("hi");
+//
+//     &s
+// } // this fails with "missing lifetime specifier" / "this function's return type contains a
+// borrowed value, but there is no value for it to be borrowed from"
+//
+// Here's what's really happening: `s` is created inside `dangle`, so when `dangle`'s code is
+// finished, `s` will go out of scope. Recall the ownership rule, when the owner goes out of
+// scope, the value is dropped. So `s` is dropped, which means its memory goes away. Returning a
+// reference to it would leave us pointing to memory that is no longer valid, and Rust refuses to
+// let this compile.
+
+// The fix is to return the `String` directly, moving ownership out of the function instead of
+// borrowing it.
+fn no_dangle() -> String {
+    let s = String::from("hi");
+
+    s
+}